@@ -1,8 +1,11 @@
+use std::fs;
+
 use crossterm::{
     ClearType,
     Color::*,
     Terminal,
 };
+use serde::Deserialize;
 use termimad::*;
 
 use crev_lib::VerificationStatus;
@@ -20,6 +23,7 @@ struct DepTableSkin {
     medium: CompoundStyle,
     good: CompoundStyle,
     none: CompoundStyle,
+    selected: CompoundStyle,
 }
 
 impl Default for DepTableSkin {
@@ -30,23 +34,162 @@ impl Default for DepTableSkin {
             medium: CompoundStyle::with_fg(Yellow),
             good: CompoundStyle::with_fg(Green),
             none: CompoundStyle::with_fg(gray(10)),
+            selected: CompoundStyle::with_fgbg(Black, AnsiValue(178)),
+        }
+    }
+}
+
+impl DepTableSkin {
+    /// Build the skin from a loaded theme, falling back to `default()` for any missing field.
+    fn from_theme(theme: &VerifyTheme) -> Self {
+        let default = Self::default();
+        Self {
+            std: theme.std.as_deref().and_then(parse_color).map_or(default.std, CompoundStyle::with_fg),
+            bad: theme.bad.as_deref().and_then(parse_color).map_or(default.bad, |c| CompoundStyle::with_fgbg(White, c)),
+            medium: theme.medium.as_deref().and_then(parse_color).map_or(default.medium, CompoundStyle::with_fg),
+            good: theme.good.as_deref().and_then(parse_color).map_or(default.good, CompoundStyle::with_fg),
+            none: theme.none.as_deref().and_then(parse_color).map_or(default.none, CompoundStyle::with_fg),
+            selected: theme.selected.as_deref().and_then(parse_color).map_or(default.selected, |c| CompoundStyle::with_fgbg(Black, c)),
         }
     }
 }
 
+/// Parse a color as used in the theme file: a handful of named colors, or the
+/// `ansi:N` / `rgb:r,g,b` forms for anything the named set doesn't cover.
+fn parse_color(s: &str) -> Option<Color> {
+    let s = s.trim();
+    if let Some(n) = s.strip_prefix("ansi:") {
+        return n.parse::<u8>().ok().map(AnsiValue);
+    }
+    if let Some(rgb) = s.strip_prefix("rgb:") {
+        let mut parts = rgb.split(',').map(|p| p.trim().parse::<u8>());
+        return match (parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(r)), Some(Ok(g)), Some(Ok(b))) => Some(Rgb { r, g, b }),
+            _ => None,
+        };
+    }
+    match s.to_lowercase().as_str() {
+        "black" => Some(Black),
+        "red" => Some(Red),
+        "green" => Some(Green),
+        "yellow" => Some(Yellow),
+        "blue" => Some(Blue),
+        "magenta" => Some(Magenta),
+        "cyan" => Some(Cyan),
+        "white" => Some(White),
+        "grey" | "gray" => Some(gray(10)),
+        _ => None,
+    }
+}
+
+/// Per-column overrides: whether it's shown at all, and its min/max width range.
+#[derive(Deserialize, Default)]
+struct VerifyThemeColumn {
+    #[serde(default)]
+    hidden: bool,
+    min_width: Option<u16>,
+    max_width: Option<u16>,
+}
+
+/// User-configurable colors and column layout for `VerifyScreen`, loaded from a
+/// TOML file in crev's config directory. Any field left unset keeps its default.
+///
+/// FIXME: this is the first use of `serde`/`derive`, `toml`, and `dirs` anywhere
+/// in `cargo-crev`, and `Cargo.toml` has not been updated to depend on them as
+/// part of this change — this does not build until that's done. Not safe to
+/// merge as-is; track the manifest update as its own follow-up rather than
+/// assuming it's covered here.
+#[derive(Deserialize, Default)]
+struct VerifyTheme {
+    std: Option<String>,
+    bad: Option<String>,
+    medium: Option<String>,
+    good: Option<String>,
+    none: Option<String>,
+    selected: Option<String>,
+    header_fg: Option<String>,
+    status_bg: Option<String>,
+    status_fg: Option<String>,
+    scrollbar_fg: Option<String>,
+    #[serde(default)]
+    columns: std::collections::HashMap<String, VerifyThemeColumn>,
+}
+
+impl VerifyTheme {
+    /// Load `verify_theme.toml` from crev's config directory, falling back to
+    /// defaults (an empty theme) when the file is absent, unreadable, or malformed.
+    fn load() -> Self {
+        Self::theme_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+    fn theme_path() -> Option<std::path::PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("crev").join("verify_theme.toml"))
+    }
+    fn is_column_visible(&self, name: &str) -> bool {
+        self.columns.get(name).map_or(true, |c| !c.hidden)
+    }
+    fn column_width(&self, name: &str, default_min: u16, default_max: u16) -> (u16, u16) {
+        match self.columns.get(name) {
+            Some(c) => (c.min_width.unwrap_or(default_min), c.max_width.unwrap_or(default_max)),
+            None => (default_min, default_max),
+        }
+    }
+}
+
+/// What the screen is currently showing in its main area.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// the usual scrollable table of dependencies
+    Table,
+    /// a drill-down view of the dependency at `selected`
+    Detail,
+    /// the full-screen keybinding help overlay
+    Help,
+}
+
 pub struct VerifyScreen<'t> {
     pub title: String,
     title_area: Area,
     status_area: Area,
     input_area: Area,
     hint_area: Area,
+    detail_area: Area,
+    preview_area: Area,
     table_view: TableView<'t, Dep>,
     skin: MadSkin,
     status_skin: MadSkin,
+    detail_skin: MadSkin,
+    help_skin: MadSkin,
+    preview_skin: MadSkin,
     last_dimensions: (u16, u16),
+    mode: Mode,
+    /// the mode to restore when the help overlay (`?`/F1) is dismissed
+    mode_before_help: Mode,
+    /// index of the selected row in `table_view`, if any rows are visible yet
+    selected: usize,
+    /// whether `/` filtering is currently capturing keystrokes
+    filtering: bool,
+    /// the fuzzy filter query typed so far
+    filter_query: String,
+    /// set when `filter_query` changed and the filter needs recomputing
+    filter_dirty: bool,
+    /// number of rows currently matching the filter (or total rows when not filtering)
+    match_count: usize,
+    /// display position -> index into `DepTable.deps`, rebuilt by `apply_order`
+    /// whenever the filter, sort, or set of known deps changes
+    visible_rows: Vec<usize>,
+    /// sort key closures, one slot per column, built alongside `table_view`'s columns
+    sort_keys: Vec<Option<Box<dyn Fn(&Dep) -> Option<SortKey> + Sync>>>,
+    /// currently active sort column, if any
+    sort_column: Option<usize>,
+    sort_ascending: bool,
+    /// scroll offset within the geiger/unsafe-usage preview pane, paged independently
+    /// of the main table
+    preview_scroll: i32,
 }
 
-
 const SIZE_NAMES: &[&str] = &["", "K", "M", "G", "T", "P", "E", "Z", "Y"];
 /// format a number of as a string
 pub fn u64_to_str(mut v: u64) -> String {
@@ -61,117 +204,195 @@ pub fn u64_to_str(mut v: u64) -> String {
     format!("{}{}", v, &SIZE_NAMES[i])
 }
 
+/// Score how well `candidate` fuzzily matches `query`, or `None` if it doesn't match at all.
+///
+/// Walks `query` left to right, greedily matching characters in `candidate` in order.
+/// Rewards consecutive runs, matches right at the start, and matches right after a
+/// `-`/`_`/word boundary, so e.g. `sc` scores `serde-core` higher than it scores `discord`.
+fn fuzzy_match_score(query: &str, candidate: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0i32;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+    for (ci, &c) in candidate.iter().enumerate() {
+        if qi >= query.len() {
+            break;
+        }
+        if c != query[qi] {
+            continue;
+        }
+        score += 1;
+        if ci == 0 {
+            score += 8;
+        } else {
+            let prev = candidate[ci - 1];
+            if prev == '-' || prev == '_' || (!prev.is_alphanumeric() && prev != c) {
+                score += 6;
+            }
+        }
+        if last_match == Some(ci.wrapping_sub(1)) {
+            score += 4;
+        }
+        last_match = Some(ci);
+        qi += 1;
+    }
+    if qi == query.len() {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+/// An orderable value extracted from a `Dep` for a sortable column.
+///
+/// Distinct from the column's display `Cell`, so e.g. `downloads` sorts on the raw
+/// count rather than on the abbreviated `u64_to_str` string.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum SortKey {
+    Str(String),
+    Num(u64),
+}
+
+/// Order `VerificationStatus` by how much attention it deserves: negative trust first.
+fn trust_severity(status: VerificationStatus) -> u64 {
+    match status {
+        VerificationStatus::Negative => 0,
+        VerificationStatus::Insufficient => 1,
+        VerificationStatus::Verified => 2,
+    }
+}
+
 impl<'t> VerifyScreen<'t> {
     pub fn new() -> Result<Self> {
-        lazy_static! {
-            static ref TS: DepTableSkin = DepTableSkin::default();
-        }
+        let theme = VerifyTheme::load();
+        // Leaked rather than cached in a `lazy_static`: the skin is derived from the
+        // theme file, which should be re-read (and so able to change) every time a
+        // `VerifyScreen` is built, not frozen after the first one in the process.
+        let ts: &'static DepTableSkin = Box::leak(Box::new(DepTableSkin::from_theme(&theme)));
+
+        let (crate_min, crate_max) = theme.column_width("crate", 10, 80);
+        let (version_min, version_max) = theme.column_width("version", 9, 13);
+        let (trust_min, trust_max) = theme.column_width("trust", 6, 6);
+        let (last_trusted_min, last_trusted_max) = theme.column_width("last trusted", 12, 16);
+        let (reviews_version_min, reviews_version_max) = theme.column_width("reviews (version)", 3, 3);
+        let (reviews_total_min, reviews_total_max) = theme.column_width("reviews (total)", 3, 3);
+        let (downloads_version_min, downloads_version_max) = theme.column_width("downloads (version)", 6, 6);
+        let (downloads_total_min, downloads_total_max) = theme.column_width("downloads (total)", 6, 6);
+        let (owners_trusted_min, owners_trusted_max) = theme.column_width("owners (trusted)", 2, 2);
+        let (owners_total_min, owners_total_max) = theme.column_width("owners (total)", 3, 3);
+        let (issues_trusted_min, issues_trusted_max) = theme.column_width("issues (trusted)", 2, 2);
+        let (issues_total_min, issues_total_max) = theme.column_width("issues (total)", 3, 3);
+        let (loc_min, loc_max) = theme.column_width("l.o.c.", 6, 6);
 
         let columns = vec![
             Column::new(
                 "crate",
-                10, 80,
-                Box::new(|dep: &Dep| Cell::new(dep.name.to_string(), &TS.std)),
+                crate_min, crate_max,
+                Box::new(move |dep: &Dep| Cell::new(dep.name.to_string(), &ts.std)),
             ).with_align(Alignment::Left),
             Column::new(
                 "version",
-                9, 13,
-                Box::new(|dep: &Dep| Cell::new(dep.version.to_string(), &TS.std)),
+                version_min, version_max,
+                Box::new(move |dep: &Dep| Cell::new(dep.version.to_string(), &ts.std)),
             ).with_align(Alignment::Right),
             Column::new(
                 "trust",
-                6, 6,
-                Box::new(|dep: &Dep| {
+                trust_min, trust_max,
+                Box::new(move |dep: &Dep| {
                     if let Some(cdep) = dep.computed() {
                         match cdep.trust {
-                            VerificationStatus::Verified => Cell::new("high".to_owned(), &TS.good),
-                            VerificationStatus::Insufficient => Cell::new("none".to_owned(), &TS.none),
-                            VerificationStatus::Negative => Cell::new("NO".to_owned(), &TS.bad),
+                            VerificationStatus::Verified => Cell::new("high".to_owned(), &ts.good),
+                            VerificationStatus::Insufficient => Cell::new("none".to_owned(), &ts.none),
+                            VerificationStatus::Negative => Cell::new("NO".to_owned(), &ts.bad),
                         }
                     } else {
-                        Cell::new("?".to_string(), &TS.medium)
+                        Cell::new("?".to_string(), &ts.medium)
                     }
                 }),
             ),
             Column::new(
                 "last trusted",
-                12, 16,
-                Box::new(|dep: &Dep| Cell::new(
+                last_trusted_min, last_trusted_max,
+                Box::new(move |dep: &Dep| Cell::new(
                     dep.computed().map_or(
                         "?".to_owned(),
                         |cdep| latest_trusted_version_string(&dep.version, &cdep.latest_trusted_version)
                     ),
-                    &TS.std
+                    &ts.std
                 )),
             ).with_align(Alignment::Right),
             Column::new(
                 "reviews",
-                3, 3,
-                Box::new(|dep: &Dep| Cell::new(
+                reviews_version_min, reviews_version_max,
+                Box::new(move |dep: &Dep| Cell::new(
                     dep.computed().map_or(
                         "?".to_owned(),
                         |cdep| u64_to_str(cdep.reviews.version)
                     ),
-                    &TS.std
+                    &ts.std
                 )),
             ).with_align(Alignment::Center),
             Column::new(
                 "reviews",
-                3, 3,
-                Box::new(|dep: &Dep| Cell::new(
+                reviews_total_min, reviews_total_max,
+                Box::new(move |dep: &Dep| Cell::new(
                     dep.computed().map_or(
                         "?".to_owned(),
                         |cdep| u64_to_str(cdep.reviews.total)
                     ),
-                    &TS.std
+                    &ts.std
                 )),
             ).with_align(Alignment::Center),
             Column::new(
                 "downloads",
-                6, 6,
-                Box::new(|dep: &Dep| {
+                downloads_version_min, downloads_version_max,
+                Box::new(move |dep: &Dep| {
                     if let Some(ComputedDep{downloads:Some(downloads),..}) = dep.computed() {
                         Cell::new(
                             u64_to_str(downloads.version),
-                            if downloads.version < 1000 { &TS.medium } else  { &TS.std },
+                            if downloads.version < 1000 { &ts.medium } else  { &ts.std },
                         )
                     } else {
-                        Cell::new("".to_string(), &TS.std)
+                        Cell::new("".to_string(), &ts.std)
                     }
                 }),
             ).with_align(Alignment::Right),
             Column::new(
                 "downloads",
-                6, 6,
-                Box::new(|dep: &Dep| {
+                downloads_total_min, downloads_total_max,
+                Box::new(move |dep: &Dep| {
                     if let Some(ComputedDep{downloads:Some(downloads),..}) = dep.computed() {
                         Cell::new(
                             u64_to_str(downloads.total),
-                            if downloads.total < 1000 { &TS.medium } else  { &TS.std },
+                            if downloads.total < 1000 { &ts.medium } else  { &ts.std },
                         )
                     } else {
-                        Cell::new("".to_string(), &TS.std)
+                        Cell::new("".to_string(), &ts.std)
                     }
                 }),
             ).with_align(Alignment::Right),
             Column::new(
                 "owners",
-                2, 2,
-                Box::new(|dep: &Dep| {
+                owners_trusted_min, owners_trusted_max,
+                Box::new(move |dep: &Dep| {
                     match dep.computed() {
                         Some(ComputedDep{owners:Some(owners),..}) if owners.trusted > 0 => {
-                            Cell::new(format!("{}", owners.trusted), &TS.good)
+                            Cell::new(format!("{}", owners.trusted), &ts.good)
                         }
                         _ => {
-                            Cell::new("".to_owned(), &TS.std)
+                            Cell::new("".to_owned(), &ts.std)
                         }
                     }
                 }),
             ).with_align(Alignment::Right),
             Column::new(
                 "owners",
-                3, 3,
-                Box::new(|dep: &Dep| {
+                owners_total_min, owners_total_max,
+                Box::new(move |dep: &Dep| {
                     Cell::new(
                         match dep.computed() {
                             Some(ComputedDep{owners:Some(owners),..}) if owners.total > 0 => {
@@ -179,53 +400,123 @@ impl<'t> VerifyScreen<'t> {
                             }
                             _ => "".to_owned(),
                         },
-                        &TS.std
+                        &ts.std
                     )
                 }),
             ).with_align(Alignment::Right),
             Column::new(
                 "issues",
-                2, 2,
-                Box::new(|dep: &Dep| {
+                issues_trusted_min, issues_trusted_max,
+                Box::new(move |dep: &Dep| {
                     match dep.computed() {
                         Some(ComputedDep{issues,..}) if issues.trusted > 0 => {
-                            Cell::new(format!("{}", issues.trusted), &TS.bad)
+                            Cell::new(format!("{}", issues.trusted), &ts.bad)
                         }
                         _ => {
-                            Cell::new("".to_owned(), &TS.std)
+                            Cell::new("".to_owned(), &ts.std)
                         }
                     }
                 }),
             ).with_align(Alignment::Right),
             Column::new(
                 "issues",
-                3, 3,
-                Box::new(|dep: &Dep| {
+                issues_total_min, issues_total_max,
+                Box::new(move |dep: &Dep| {
                     match dep.computed() {
                         Some(ComputedDep{issues,..}) if issues.total > 0 => {
-                            Cell::new(format!("{}", issues.total), &TS.medium)
+                            Cell::new(format!("{}", issues.total), &ts.medium)
                         }
                         _ => {
-                            Cell::new("".to_owned(), &TS.std)
+                            Cell::new("".to_owned(), &ts.std)
                         }
                     }
                 }),
             ).with_align(Alignment::Right),
             Column::new(
                 "l.o.c.",
-                6, 6,
-                Box::new(|dep: &Dep| {
+                loc_min, loc_max,
+                Box::new(move |dep: &Dep| {
                     Cell::new(
                         match dep.computed() {
                             Some(ComputedDep{loc:Some(loc),..}) => u64_to_str(*loc as u64),
                             _ => "".to_string(),
                         },
-                        &TS.std
+                        &ts.std
                     )
                 }),
             ).with_align(Alignment::Right),
         ];
 
+        // Sort keys, one slot per column above, in the same order; `None` means the
+        // column isn't sortable. Kept distinct from the `Cell` closures so numeric
+        // columns sort on their raw value rather than on the abbreviated display string.
+        let sort_keys: Vec<Option<Box<dyn Fn(&Dep) -> Option<SortKey> + Sync>>> = vec![
+            Some(Box::new(|dep: &Dep| Some(SortKey::Str(dep.name.to_string())))),
+            Some(Box::new(|dep: &Dep| Some(SortKey::Str(dep.version.to_string())))),
+            Some(Box::new(|dep: &Dep| dep.computed().map(|cdep| SortKey::Num(trust_severity(cdep.trust))))),
+            None, // last trusted
+            Some(Box::new(|dep: &Dep| dep.computed().map(|cdep| SortKey::Num(cdep.reviews.version)))),
+            Some(Box::new(|dep: &Dep| dep.computed().map(|cdep| SortKey::Num(cdep.reviews.total)))),
+            Some(Box::new(|dep: &Dep| {
+                if let Some(ComputedDep{downloads:Some(downloads),..}) = dep.computed() {
+                    Some(SortKey::Num(downloads.version))
+                } else {
+                    None
+                }
+            })),
+            Some(Box::new(|dep: &Dep| {
+                if let Some(ComputedDep{downloads:Some(downloads),..}) = dep.computed() {
+                    Some(SortKey::Num(downloads.total))
+                } else {
+                    None
+                }
+            })),
+            Some(Box::new(|dep: &Dep| {
+                if let Some(ComputedDep{owners:Some(owners),..}) = dep.computed() {
+                    Some(SortKey::Num(owners.trusted as u64))
+                } else {
+                    None
+                }
+            })),
+            Some(Box::new(|dep: &Dep| {
+                if let Some(ComputedDep{owners:Some(owners),..}) = dep.computed() {
+                    Some(SortKey::Num(owners.total as u64))
+                } else {
+                    None
+                }
+            })),
+            Some(Box::new(|dep: &Dep| dep.computed().map(|cdep| SortKey::Num(cdep.issues.trusted as u64)))),
+            Some(Box::new(|dep: &Dep| dep.computed().map(|cdep| SortKey::Num(cdep.issues.total as u64)))),
+            Some(Box::new(|dep: &Dep| {
+                if let Some(ComputedDep{loc:Some(loc),..}) = dep.computed() {
+                    Some(SortKey::Num(*loc as u64))
+                } else {
+                    None
+                }
+            })),
+        ];
+
+        // Internal names used to key the theme's `[columns.*]` overrides; these don't
+        // need to match the (sometimes duplicated) rendered headers above.
+        const COLUMN_NAMES: &[&str] = &[
+            "crate", "version", "trust", "last trusted",
+            "reviews (version)", "reviews (total)",
+            "downloads (version)", "downloads (total)",
+            "owners (trusted)", "owners (total)",
+            "issues (trusted)", "issues (total)",
+            "l.o.c.",
+        ];
+        let mut visible_columns = Vec::with_capacity(columns.len());
+        let mut visible_sort_keys = Vec::with_capacity(sort_keys.len());
+        for ((column, sort_key), name) in columns.into_iter().zip(sort_keys.into_iter()).zip(COLUMN_NAMES.iter()) {
+            if theme.is_column_visible(name) {
+                visible_columns.push(column);
+                visible_sort_keys.push(sort_key);
+            }
+        }
+        let columns = visible_columns;
+        let sort_keys = visible_sort_keys;
+
         let table_view = TableView::new(
             Area::new(0, 1, 10, 10),
             columns,
@@ -239,23 +530,55 @@ impl<'t> VerifyScreen<'t> {
             status_area: Area::new(0, 2, 10, 1),
             input_area: Area::new(0, 3, 10, 1),
             hint_area: Area::new(0, 3, 10, 1),
+            detail_area: Area::new(0, 1, 10, 10),
+            preview_area: Area::new(0, 1, 10, 10),
             table_view,
             skin: MadSkin::default(),
             status_skin: MadSkin::default(),
+            detail_skin: MadSkin::default(),
+            help_skin: MadSkin::default(),
+            preview_skin: MadSkin::default(),
             last_dimensions: (0, 0),
+            mode: Mode::Table,
+            mode_before_help: Mode::Table,
+            selected: 0,
+            filtering: false,
+            filter_query: String::new(),
+            filter_dirty: false,
+            match_count: 0,
+            visible_rows: Vec::new(),
+            sort_keys,
+            sort_column: None,
+            sort_ascending: true,
+            preview_scroll: 0,
         };
         screen.resize();
-        screen.make_skins();
+        screen.make_skins(&theme);
         Ok(screen)
     }
-    pub fn make_skins(&mut self) {
+    pub fn make_skins(&mut self, theme: &VerifyTheme) {
+        let header_fg = theme.header_fg.as_deref().and_then(parse_color).unwrap_or(AnsiValue(178));
+        let scrollbar_fg = theme.scrollbar_fg.as_deref().and_then(parse_color).unwrap_or(AnsiValue(178));
+        let status_bg = theme.status_bg.as_deref().and_then(parse_color).unwrap_or(gray(4));
+        let status_fg = theme.status_fg.as_deref().and_then(parse_color).unwrap_or(ansi(225));
+
         self.skin.table.align = Alignment::Center;
-        self.skin.set_headers_fg(AnsiValue(178));
+        self.skin.set_headers_fg(header_fg);
         self.skin.bold.set_fg(Yellow);
         self.skin.italic.set_fg(ansi(153));
-        self.skin.scrollbar.thumb.set_fg(ansi(178));
-        self.status_skin.paragraph.set_bg(gray(4));
-        self.status_skin.italic.set_fg(ansi(225));
+        self.skin.scrollbar.thumb.set_fg(scrollbar_fg);
+        self.status_skin.paragraph.set_bg(status_bg);
+        self.status_skin.italic.set_fg(status_fg);
+        self.detail_skin.set_headers_fg(header_fg);
+        self.detail_skin.bold.set_fg(Yellow);
+        self.detail_skin.italic.set_fg(ansi(153));
+        self.help_skin.set_headers_fg(header_fg);
+        self.help_skin.bold.set_fg(Yellow);
+        self.help_skin.italic.set_fg(ansi(153));
+        self.preview_skin.set_headers_fg(header_fg);
+        self.preview_skin.bold.set_fg(Yellow);
+        self.preview_skin.italic.set_fg(ansi(153));
+        self.preview_skin.scrollbar.thumb.set_fg(scrollbar_fg);
     }
     pub fn resize(&mut self) {
         let (w, h) = terminal_size();
@@ -265,9 +588,17 @@ impl<'t> VerifyScreen<'t> {
         Terminal::new().clear(ClearType::All).unwrap();
         self.last_dimensions = (w, h);
         self.title_area.width = w;
+        let content_height = h - 4;
+        let preview_height = (content_height / 3).max(5).min(content_height.saturating_sub(3));
+        let table_height = content_height - preview_height;
         self.table_view.area.width = w;
-        self.table_view.area.height = h - 4;
+        self.table_view.area.height = table_height;
         self.table_view.update_dimensions();
+        self.detail_area.width = w;
+        self.detail_area.height = content_height;
+        self.preview_area.top = self.table_view.area.top + table_height;
+        self.preview_area.width = w;
+        self.preview_area.height = preview_height;
         self.status_area.top = h - 3;
         self.status_area.width = w;
         self.input_area.top = h - 2;
@@ -290,15 +621,327 @@ impl<'t> VerifyScreen<'t> {
             ).unwrap();
         } else {
             let iab = self.table_view.do_scroll_show_bottom();
-            for i in self.table_view.row_count()..table.deps.len() {
+            let known_before = self.table_view.row_count();
+            for i in known_before..table.deps.len() {
                 self.table_view.add_row(&table.deps[i]);
             }
+            if table.deps.len() > known_before {
+                // newly-arrived deps shift `visible_rows`; recompute it even if the
+                // user hasn't touched the filter or sort since the last frame
+                self.filter_dirty = true;
+            }
             if iab {
                 self.table_view.scroll_to_bottom();
             }
+            let selected_before = self.visible_rows.get(self.selected).copied();
+            if self.filter_dirty {
+                self.apply_order(table);
+                self.filter_dirty = false;
+            }
+            self.clamp_selection();
+            if self.visible_rows.get(self.selected).copied() != selected_before {
+                // The crate under the cursor can change out from under it when a filter or
+                // sort reorders the visible rows, so the old scroll offset into its geiger
+                // listing no longer means anything; reset it like the explicit navigation
+                // methods below do.
+                self.preview_scroll = 0;
+            }
+            self.table_view.scroll_to_show_row(self.selected);
+            self.table_view.set_selected(Some(self.selected));
             self.table_view.display().unwrap();
+            self.update_preview(table);
         }
     }
+    /// Render the geiger/unsafe-usage preview pane for the currently selected dependency.
+    ///
+    /// Shows a "computing..." placeholder until `computation_status` has moved past the
+    /// `ComputingGeiger` stage, since geiger data arrives incrementally.
+    fn update_preview(&self, table: &DepTable) {
+        let dep = match self.selected_dep(table) {
+            Some(dep) => dep,
+            None => {
+                self.preview_skin.write_in_area("", &self.preview_area).unwrap();
+                return;
+            }
+        };
+        let geiger_ready = !matches!(
+            table.computation_status,
+            TableComputationStatus::New | TableComputationStatus::ComputingGeiger{..}
+        );
+        let text = if !geiger_ready {
+            format!("## Unsafe usage in `{}`\n\n*computing...*", dep.name)
+        } else {
+            match dep.computed().and_then(|cdep| cdep.geiger_findings.as_ref()) {
+                Some(findings) if !findings.is_empty() => {
+                    let mut md = format!("## Unsafe usage in `{}`\n\n", dep.name);
+                    for finding in findings {
+                        md.push_str(&format!(
+                            "- `{}` : {} unsafe line(s)\n",
+                            finding.file.display(), finding.unsafe_lines,
+                        ));
+                    }
+                    md
+                }
+                _ => format!("## Unsafe usage in `{}`\n\nNone flagged", dep.name),
+            }
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let start = (self.preview_scroll as usize).min(lines.len().saturating_sub(1));
+        self.preview_skin.write_in_area(&lines[start..].join("\n"), &self.preview_area).unwrap();
+    }
+    /// Page the unsafe-usage preview pane, independently of the main table's scroll.
+    pub fn try_scroll_preview(&mut self, lines_count: i32) {
+        self.preview_scroll = (self.preview_scroll + lines_count).max(0);
+    }
+    /// Recompute which rows are shown, and in what order, and hand the result to `table_view`.
+    ///
+    /// `DepTable.deps` itself is left untouched; `table_view` keeps its own row order
+    /// vector so clearing the query and sort restores the original, unfiltered list.
+    fn apply_order(&mut self, table: &DepTable) {
+        let mut rows: Vec<usize> = if self.filter_query.is_empty() {
+            (0..table.deps.len()).collect()
+        } else {
+            let mut scored: Vec<(usize, i32)> = table.deps.iter()
+                .enumerate()
+                .filter_map(|(i, dep)| {
+                    fuzzy_match_score(&self.filter_query, &dep.name).map(|score| (i, score))
+                })
+                .collect();
+            scored.sort_by(|a, b| b.1.cmp(&a.1));
+            scored.into_iter().map(|(i, _)| i).collect()
+        };
+        self.match_count = rows.len();
+        if let Some(col) = self.sort_column {
+            if let Some(key_fn) = &self.sort_keys[col] {
+                let ascending = self.sort_ascending;
+                rows.sort_by(|&a, &b| {
+                    let ka = key_fn(&table.deps[a]);
+                    let kb = key_fn(&table.deps[b]);
+                    match (ka, kb) {
+                        (None, None) => std::cmp::Ordering::Equal,
+                        (None, Some(_)) => std::cmp::Ordering::Greater,
+                        (Some(_), None) => std::cmp::Ordering::Less,
+                        (Some(ka), Some(kb)) => if ascending { ka.cmp(&kb) } else { kb.cmp(&ka) },
+                    }
+                });
+            }
+        }
+        if self.filter_query.is_empty() && self.sort_column.is_none() {
+            self.table_view.clear_row_order();
+        } else {
+            self.table_view.set_row_order(rows.clone());
+        }
+        self.visible_rows = rows;
+    }
+    /// Start capturing a `/` fuzzy filter query.
+    pub fn start_filter(&mut self) {
+        self.filtering = true;
+        self.filter_query.clear();
+        self.filter_dirty = true;
+    }
+    /// Append a typed character to the filter query.
+    pub fn push_filter_char(&mut self, c: char) {
+        self.filter_query.push(c);
+        self.filter_dirty = true;
+    }
+    /// Remove the last character of the filter query (backspace).
+    pub fn pop_filter_char(&mut self) {
+        self.filter_query.pop();
+        self.filter_dirty = true;
+    }
+    /// Cancel filtering (Esc), restoring the full, unfiltered table.
+    pub fn cancel_filter(&mut self) {
+        self.filtering = false;
+        self.filter_query.clear();
+        self.filter_dirty = true;
+    }
+    /// Cycle the active sort column forward (`s`) or backward (`S`), skipping
+    /// non-sortable columns, toggling direction when landing back on the same column.
+    pub fn cycle_sort_column(&mut self, forward: bool) {
+        let n = self.sort_keys.len();
+        if n == 0 {
+            return;
+        }
+        let start = self.sort_column.unwrap_or(if forward { n - 1 } else { 0 });
+        let mut col = start;
+        for _ in 0..n {
+            col = if forward { (col + 1) % n } else { (col + n - 1) % n };
+            if self.sort_keys[col].is_some() {
+                if self.sort_column == Some(col) {
+                    self.sort_ascending = !self.sort_ascending;
+                } else {
+                    self.sort_column = Some(col);
+                    self.sort_ascending = true;
+                }
+                self.filter_dirty = true;
+                self.update_sort_header();
+                return;
+            }
+        }
+    }
+    /// Toggle ascending/descending order on the current sort column.
+    pub fn toggle_sort_direction(&mut self) {
+        if self.sort_column.is_some() {
+            self.sort_ascending = !self.sort_ascending;
+            self.filter_dirty = true;
+            self.update_sort_header();
+        }
+    }
+    /// Mark the active sort column's header with an arrow glyph, if any.
+    fn update_sort_header(&mut self) {
+        let arrow = if self.sort_ascending { "▲" } else { "▼" };
+        self.table_view.set_header_suffix(self.sort_column.map(|col| (col, arrow)));
+    }
+    /// The `Dep` at the current display-order selection, mapped through `visible_rows`
+    /// back to its real index in `DepTable.deps`.
+    fn selected_dep<'d>(&self, table: &'d DepTable) -> Option<&'d Dep> {
+        let index = *self.visible_rows.get(self.selected)?;
+        table.deps.get(index)
+    }
+    /// Render the detail sub-screen for the dependency currently selected in the table.
+    fn update_detail(&self, table: &DepTable) {
+        let dep = match self.selected_dep(table) {
+            Some(dep) => dep,
+            None => return,
+        };
+        let mut md = format!("# {} {}\n\n", dep.name, dep.version);
+        match dep.computed() {
+            Some(cdep) => {
+                md.push_str(&match cdep.trust {
+                    VerificationStatus::Verified => "**trust**: high\n\n".to_owned(),
+                    VerificationStatus::Insufficient => "**trust**: none\n\n".to_owned(),
+                    VerificationStatus::Negative => "**trust**: NO\n\n".to_owned(),
+                });
+                if cdep.reviewers.is_empty() {
+                    md.push_str("**reviewers**: none\n\n");
+                } else {
+                    md.push_str("**reviewers**:\n\n");
+                    for reviewer in &cdep.reviewers {
+                        md.push_str(&format!("- {}\n", reviewer));
+                    }
+                    md.push('\n');
+                }
+                md.push_str(&format!(
+                    "**last trusted version**: {}\n\n",
+                    latest_trusted_version_string(&dep.version, &cdep.latest_trusted_version),
+                ));
+                if let Some(downloads) = &cdep.downloads {
+                    md.push_str(&format!(
+                        "**downloads**: {} (version), {} (total)\n\n",
+                        downloads.version, downloads.total,
+                    ));
+                }
+                if let Some(owners) = &cdep.owners {
+                    md.push_str(&format!(
+                        "**owners**: {} trusted / {} total\n\n",
+                        owners.trusted, owners.total,
+                    ));
+                }
+                md.push_str(&format!(
+                    "**issues**: {} trusted / {} total\n\n",
+                    cdep.issues.trusted, cdep.issues.total,
+                ));
+                if let Some(loc) = cdep.loc {
+                    md.push_str(&format!("**lines of code**: {}\n\n", loc));
+                }
+                if let Some(geiger) = cdep.geiger {
+                    md.push_str(&format!("**geiger (unsafe) count**: {}\n\n", geiger));
+                }
+            }
+            None => {
+                md.push_str("*computation not finished yet*\n\n");
+            }
+        }
+        md.push_str("\nHit *Esc* to return to the table");
+        self.detail_skin.write_in_area(&md, &self.detail_area).unwrap();
+    }
+    fn clamp_selection(&mut self) {
+        let len = self.visible_rows.len();
+        if len == 0 {
+            self.selected = 0;
+        } else if self.selected >= len {
+            self.selected = len - 1;
+        }
+    }
+    /// Move the selection cursor by `delta` rows, clamping to the visible row range.
+    pub fn move_selection(&mut self, delta: i32) {
+        let len = self.visible_rows.len();
+        if len == 0 {
+            return;
+        }
+        let new_pos = (self.selected as i32 + delta).max(0) as usize;
+        self.selected = new_pos.min(len - 1);
+        self.preview_scroll = 0;
+    }
+    /// Move the selection to the first visible row.
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+        self.preview_scroll = 0;
+    }
+    /// Move the selection to the last visible row.
+    pub fn select_last(&mut self) {
+        let len = self.visible_rows.len();
+        self.selected = len.saturating_sub(1);
+        self.preview_scroll = 0;
+    }
+    /// Open the detail sub-screen for the currently selected row.
+    pub fn open_detail(&mut self) {
+        if !self.visible_rows.is_empty() {
+            self.mode = Mode::Detail;
+        }
+    }
+    /// Leave the detail sub-screen, returning to the table.
+    pub fn close_detail(&mut self) {
+        self.mode = Mode::Table;
+    }
+    /// Open the full-screen keybinding help overlay (`?`/F1), suspending normal rendering.
+    pub fn open_help(&mut self) {
+        if self.mode != Mode::Help {
+            self.mode_before_help = self.mode;
+            self.mode = Mode::Help;
+        }
+    }
+    /// Dismiss the help overlay (`?`/F1/Esc), restoring whatever was shown before it.
+    pub fn close_help(&mut self) {
+        self.mode = self.mode_before_help;
+    }
+    /// Toggle the help overlay.
+    pub fn toggle_help(&mut self) {
+        if self.mode == Mode::Help {
+            self.close_help();
+        } else {
+            self.open_help();
+        }
+    }
+    fn update_help(&self) {
+        let help = "\
+# Keybindings
+
+## Navigation
+*↑* / *↓*         move the selection
+*Home* / *End*    jump to first / last row
+*PageUp* / *PageDown*  scroll a page
+*Enter*           inspect the selected dependency
+*Esc*             back out of detail / filter / this help
+
+## Filter
+*/*               start a fuzzy filter by crate name
+*Esc*             clear the filter and show all rows again
+
+## Sort
+*s* / *S*         cycle the sort column forward / backward
+(each newly chosen column starts in ascending order)
+
+## Unsafe usage preview
+below the table, shows flagged `unsafe` file/line counts for the selected row
+*PageUp* / *PageDown* scroll the table; the preview scrolls independently
+
+## General
+*?* / *F1*        toggle this help
+*ctrl-q*          quit
+";
+        self.help_skin.write_in_area(help, &self.table_view.area).unwrap();
+    }
     fn update_status(&self, table: &DepTable) {
         let status = match table.computation_status {
             TableComputationStatus::New => {
@@ -320,25 +963,43 @@ impl<'t> VerifyScreen<'t> {
         ).unwrap();
     }
     fn update_input(&self, _table: &DepTable) {
-        // temporary. Main purpose is to clean the area (in case of resize)
-        self.skin.write_in_area("", &self.input_area).unwrap();
+        // also cleans the area (in case of resize) when not filtering
+        let text = if self.filtering {
+            format!("/{}", &self.filter_query)
+        } else {
+            "".to_owned()
+        };
+        self.skin.write_in_area(&text, &self.input_area).unwrap();
     }
     fn update_hint(&self, table: &DepTable) {
-        self.skin.write_in_area(
-            if table.computation_status.is_before_deps() {
-                "Hit *ctrl-q* to quit"
-            } else {
-                "Hit *ctrl-q* to quit, *PageUp* or *PageDown* to scroll"
-            },
-            &self.hint_area
-        ).unwrap();
+        let hint = if self.filtering {
+            format!("{} matching · *Enter*/*Esc* to stop filtering", self.match_count)
+        } else {
+            match (&self.mode, table.computation_status.is_before_deps()) {
+                (_, true) => "Hit *ctrl-q* to quit".to_owned(),
+                (Mode::Detail, false) => "Hit *Esc* to return to the table".to_owned(),
+                (Mode::Help, false) => "Hit *Esc* or *?* to close this help".to_owned(),
+                (Mode::Table, false) => "Hit *ctrl-q* to quit, *↑*/*↓* to select, *Enter* to inspect, */* to filter, *s*/*S* to sort, *?* for help".to_owned(),
+            }
+        };
+        self.skin.write_in_area(&hint, &self.hint_area).unwrap();
     }
     pub fn update_for(&mut self, table: &DepTable) {
         self.resize();
-        self.update_title(table);
-        self.update_table_view(table);
-        self.update_status(table);
-        self.update_input(table);
+        match self.mode {
+            Mode::Table => {
+                self.update_title(table);
+                self.update_table_view(table);
+                self.update_status(table);
+                self.update_input(table);
+            }
+            Mode::Detail => {
+                self.update_detail(table);
+            }
+            Mode::Help => {
+                self.update_help();
+            }
+        }
         self.update_hint(table);
     }
     #[allow(dead_code)]